@@ -1,4 +1,7 @@
 use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::{
     error::ParserError,
@@ -37,7 +40,7 @@ impl<'a> CircularBuffer<'a> {
             return false;
         }
         self.buf[self.tail] = data;
-        self.tail += 1;
+        self.tail = (self.tail + 1) % self.buf.len();
         true
     }
 
@@ -47,20 +50,33 @@ impl<'a> CircularBuffer<'a> {
             return None;
         }
         let x = self.buf[self.head];
-        self.head += 1;
+        self.head = (self.head + 1) % self.buf.len();
         Some(x)
     }
 
+    /// Discards up to `n` elements from the front of the buffer
+    fn advance(&mut self, n: usize) {
+        for _ in 0..n.min(self.len()) {
+            self.pop();
+        }
+    }
+
     /// Returns the element at the given index, panicing if the index is invalid
-    fn at(&mut self, idx: usize) -> u8 {
-        assert!(idx >= 0 && idx < self.len());
-        let idx = self.head + idx;
-        let idx = if idx >= self.len() {
-            idx - self.len()
+    fn at(&self, idx: usize) -> u8 {
+        assert!(idx < self.len());
+        self.buf[(self.head + idx) % self.buf.len()]
+    }
+
+    /// Returns a contiguous slice covering `[start, start + len)` logical bytes,
+    /// or `None` if that range straddles the physical end of the backing storage
+    fn as_contiguous_slice(&self, start: usize, len: usize) -> Option<&[u8]> {
+        let capacity = self.buf.len();
+        let phys_start = (self.head + start) % capacity;
+        if phys_start + len <= capacity {
+            Some(&self.buf[phys_start..phys_start + len])
         } else {
-            idx
-        };
-        self.buf[idx]
+            None
+        }
     }
 
     fn iter(&'a mut self) -> CircularBufferIter<'_, 'a> {
@@ -142,32 +158,681 @@ mod test {
             assert_eq!(a, *b);
         }
     }
+
+    #[test]
+    fn cb_wraps_around_physical_end() {
+        let mut buf = [0; 5];
+        let mut buf = CircularBuffer::new(&mut buf);
+        for i in 0..3 {
+            assert_eq!(buf.push(i), true);
+        }
+        for i in 0..3 {
+            assert_eq!(buf.pop(), Some(i));
+        }
+        // tail and head both sit at physical offset 3 now, so the pushes and
+        // pops below wrap past the physical end of `buf`
+        for i in 10..14 {
+            assert_eq!(buf.push(i), true);
+        }
+        assert_eq!(buf.len(), 4);
+        for i in 10..14 {
+            assert_eq!(buf.pop(), Some(i));
+        }
+    }
+
+    #[test]
+    fn cb_as_contiguous_slice_detects_wrap() {
+        let mut buf = [0; 5];
+        let mut buf = CircularBuffer::new(&mut buf);
+        for i in 0..3 {
+            buf.push(i);
+        }
+        buf.advance(3);
+        for i in 10..14 {
+            buf.push(i);
+        }
+        // logical bytes [10, 11, 12, 13] now straddle the physical end of the
+        // 5-byte backing array, so no contiguous view is available
+        assert_eq!(buf.as_contiguous_slice(0, 4), None);
+        assert_eq!(buf.as_contiguous_slice(0, 1), Some(&[10][..]));
+    }
+}
+
+/// Number of bytes of scratch space needed to hold the largest possible
+/// frame (class, msg id, length, payload, checksum) contiguously when it
+/// straddles the wrap point of the backing ring buffer.
+const SCRATCH_LEN: usize = MAX_PAYLOAD_LEN as usize + 8;
+
+/// Where a candidate frame was found while scanning, shared by the scanning
+/// step of [`ParserIter`] and [`BufParserIter`]
+struct FrameLocation {
+    /// Absolute offset of SYNC_CHAR_1
+    pos: usize,
+    /// Payload length as read from the frame header
+    pack_len: usize,
+    /// Total frame length, sync bytes through checksum
+    frame_len: usize,
+}
+
+/// Counters tracking how much resynchronization and data loss a parser has
+/// had to do, for diagnosing a noisy link. Cumulative for the lifetime of
+/// the owning [`Parser`]/[`BufParser`]; nothing here resets between calls.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParserStats {
+    /// Bytes discarded while resyncing past a byte (or run of bytes) that
+    /// didn't turn out to start a valid frame
+    pub bytes_skipped: usize,
+    /// Number of `consume` calls in which the buffer overflowed and the
+    /// configured [`OverflowPolicy`] discarded bytes: the oldest buffered
+    /// ones, or under `Error` the incoming ones that did not fit
+    pub dropped_frames: usize,
+    /// Frames whose checksum (UBX, NMEA, or RTCM3) failed to verify
+    pub checksum_failures: usize,
+    /// The largest the buffer has grown to, in bytes, since this parser was
+    /// created
+    pub high_water_mark: usize,
+}
+
+/// What a parser should do when its buffer has no room for new bytes and no
+/// complete frame has been found yet.
+///
+/// `BufParser`'s backing storage is a fixed-size slice and can never
+/// actually grow, so `GrowToCap` behaves the same as `DropOldest` there;
+/// the distinction only matters for the heap-backed [`Parser`], where it
+/// lets the buffer grow past its usual size (up to [`GROW_TO_CAP_FACTOR`]
+/// times `cap`) before the cap takes effect and trims it back down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Discard the oldest buffered bytes to make room for new ones
+    DropOldest(usize),
+    /// Stop buffering new bytes once at the cap, counting the overflow in
+    /// [`ParserStats::dropped_frames`] instead of silently discarding the
+    /// bytes already held
+    Error(usize),
+    /// Allow the buffer to grow past `cap` (up to [`GROW_TO_CAP_FACTOR`]
+    /// times `cap`) before falling back to `DropOldest` semantics and
+    /// trimming back down to `cap`
+    GrowToCap(usize),
+}
+
+impl OverflowPolicy {
+    fn cap(self) -> usize {
+        match self {
+            OverflowPolicy::DropOldest(cap)
+            | OverflowPolicy::Error(cap)
+            | OverflowPolicy::GrowToCap(cap) => cap,
+        }
+    }
+
+    /// The buffer length at which this policy's overflow handling actually
+    /// kicks in. Equal to `cap` for `DropOldest`/`Error`; `GrowToCap` instead
+    /// tolerates growth up to `GROW_TO_CAP_FACTOR * cap` before falling back
+    /// to the same drop-oldest trim, which is what lets it grow past its
+    /// usual size on the heap-backed `Parser`.
+    fn overflow_threshold(self) -> usize {
+        match self {
+            OverflowPolicy::DropOldest(cap) | OverflowPolicy::Error(cap) => cap,
+            OverflowPolicy::GrowToCap(cap) => cap.saturating_mul(GROW_TO_CAP_FACTOR),
+        }
+    }
+}
+
+/// How far past its cap `OverflowPolicy::GrowToCap` lets the heap-backed
+/// `Parser`'s buffer grow before trimming back down to `cap`. Chosen
+/// arbitrarily as a small multiple; `BufParser` ignores this entirely since
+/// its backing slice can't grow past its physical capacity regardless.
+const GROW_TO_CAP_FACTOR: usize = 2;
+
+impl Default for OverflowPolicy {
+    /// Unbounded growth, matching the buffer's behavior before
+    /// `OverflowPolicy` existed
+    fn default() -> Self {
+        OverflowPolicy::GrowToCap(usize::MAX)
+    }
+}
+
+const NMEA_START_1: u8 = b'$';
+const NMEA_START_2: u8 = b'!';
+const NMEA_END: [u8; 2] = [b'\r', b'\n'];
+/// Generous cap on a single NMEA sentence, well above the 82-byte limit in
+/// the NMEA 0183 spec, used only to bound how far `attempt_nmea` scans
+/// looking for a terminator before giving up on a false-positive `$`/`!`.
+const MAX_NMEA_LEN: usize = 128;
+
+const RTCM3_PREAMBLE: u8 = 0xD3;
+/// RTCM3's CRC-24Q polynomial
+const RTCM3_CRC24Q_POLY: u32 = 0x0186_4CFB;
+
+/// A single demultiplexed frame from a byte stream carrying UBX, NMEA, and
+/// RTCM3 interleaved on the same port
+pub enum Frame<'a> {
+    Ubx(PacketRef<'a>),
+    /// A complete NMEA sentence, `$`/`!` through the trailing `\r\n`
+    Nmea(&'a str),
+    /// A complete RTCM3 frame, preamble through the CRC-24Q trailer
+    Rtcm3(&'a [u8]),
 }
 
+/// Outcome of trying to parse a frame out of `data`, whose first byte has
+/// already matched one of the three framing markers
+enum FrameAttempt<'d> {
+    /// Not enough bytes buffered yet to tell one way or the other; the
+    /// caller should stop and wait for more data rather than advance
+    Incomplete,
+    /// `data[0]` was not actually the start of a valid frame
+    Invalid,
+    /// A complete frame `frame_len` bytes long, successfully parsed or not
+    Frame {
+        frame_len: usize,
+        result: Result<Frame<'d>, ParserError>,
+    },
+}
+
+fn nmea_checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |acc, &b| acc ^ b)
+}
+
+/// CRC-24Q over `data`, MSB-first, initial value 0, no input/output reflection
+fn rtcm3_crc24q(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0;
+    for &byte in data {
+        crc ^= (byte as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x0100_0000 != 0 {
+                crc ^= RTCM3_CRC24Q_POLY;
+            }
+        }
+    }
+    crc & 0x00FF_FFFF
+}
+
+fn attempt_ubx(data: &[u8]) -> FrameAttempt<'_> {
+    if data.len() < 2 {
+        return FrameAttempt::Incomplete;
+    }
+    if data[1] != SYNC_CHAR_2 {
+        return FrameAttempt::Invalid;
+    }
+    if data.len() < 6 {
+        return FrameAttempt::Incomplete;
+    }
+    let pack_len: usize = u16::from_le_bytes([data[4], data[5]]).into();
+    if pack_len > usize::from(MAX_PAYLOAD_LEN) {
+        return FrameAttempt::Invalid;
+    }
+    let frame_len = 8 + pack_len;
+    if data.len() < frame_len {
+        return FrameAttempt::Incomplete;
+    }
+
+    let (ck_a, ck_b) = ubx_checksum(&data[2..(4 + pack_len + 2)]);
+    let (expect_ck_a, expect_ck_b) = (data[6 + pack_len], data[6 + pack_len + 1]);
+    if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
+        return FrameAttempt::Frame {
+            frame_len,
+            result: Err(ParserError::InvalidChecksum {
+                expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
+                got: u16::from_le_bytes([ck_a, ck_b]),
+            }),
+        };
+    }
+
+    let msg_data = &data[6..(6 + pack_len)];
+    FrameAttempt::Frame {
+        frame_len,
+        result: match_packet(data[2], data[3], msg_data).map(Frame::Ubx),
+    }
+}
+
+fn attempt_nmea(data: &[u8]) -> FrameAttempt<'_> {
+    let end = match data.windows(2).position(|w| w == NMEA_END) {
+        Some(end) => end,
+        None if data.len() > MAX_NMEA_LEN => return FrameAttempt::Invalid,
+        None => return FrameAttempt::Incomplete,
+    };
+    let frame_len = end + 2;
+
+    let body = &data[1..end];
+    let checksum = match body.iter().position(|&b| b == b'*') {
+        Some(star) if star + 3 <= body.len() => {
+            let computed = nmea_checksum(&body[..star]);
+            let expected = core::str::from_utf8(&body[star + 1..star + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            Some((expected, computed))
+        }
+        _ => None,
+    };
+
+    let sentence = match core::str::from_utf8(&data[..frame_len]) {
+        Ok(s) => s,
+        Err(_) => return FrameAttempt::Invalid,
+    };
+
+    if let Some((expected, computed)) = checksum {
+        if expected != Some(computed) {
+            return FrameAttempt::Frame {
+                frame_len,
+                result: Err(ParserError::InvalidChecksum {
+                    expect: u16::from(expected.unwrap_or_default()),
+                    got: u16::from(computed),
+                }),
+            };
+        }
+    }
+
+    FrameAttempt::Frame {
+        frame_len,
+        result: Ok(Frame::Nmea(sentence)),
+    }
+}
+
+fn attempt_rtcm3(data: &[u8]) -> FrameAttempt<'_> {
+    if data.len() < 3 {
+        return FrameAttempt::Incomplete;
+    }
+    let payload_len = (usize::from(data[1] & 0x03) << 8) | usize::from(data[2]);
+    let frame_len = 3 + payload_len + 3;
+    if data.len() < frame_len {
+        return FrameAttempt::Incomplete;
+    }
+
+    let computed = rtcm3_crc24q(&data[..3 + payload_len]);
+    let expected = u32::from_be_bytes([
+        0,
+        data[3 + payload_len],
+        data[3 + payload_len + 1],
+        data[3 + payload_len + 2],
+    ]);
+    if computed != expected {
+        // `ParserError::InvalidChecksum` only carries 16 bits, but CRC-24Q is
+        // 24 bits wide. Keep the low 16 bits and drop the high byte rather
+        // than shifting out the low byte, so two frames that differ only in
+        // their low byte (the common case for single-bit-flip corruption)
+        // don't get reported as the same expect/got pair.
+        return FrameAttempt::Frame {
+            frame_len,
+            result: Err(ParserError::InvalidChecksum {
+                expect: (expected & 0xFFFF) as u16,
+                got: (computed & 0xFFFF) as u16,
+            }),
+        };
+    }
+
+    FrameAttempt::Frame {
+        frame_len,
+        result: Ok(Frame::Rtcm3(&data[..frame_len])),
+    }
+}
+
+/// Streaming parser for UBX protocol backed by a caller-provided `&mut [u8]`,
+/// suitable for `no_std` targets without an allocator. Unlike [`Parser`],
+/// which copies consumed bytes into a growable `Vec`, `BufParser` reads
+/// directly out of a fixed-capacity [`CircularBuffer`].
 pub struct BufParser<'a> {
     buf: CircularBuffer<'a>,
+    scratch: [u8; SCRATCH_LEN],
+    stats: ParserStats,
+    policy: OverflowPolicy,
 }
 
-impl BufParser {
-    pub fn new(buf: &mut [u8]) -> BufParser {
+impl<'a> BufParser<'a> {
+    pub fn new(buf: &'a mut [u8]) -> BufParser<'a> {
         BufParser {
             buf: CircularBuffer::new(buf),
+            scratch: [0; SCRATCH_LEN],
+            stats: ParserStats::default(),
+            policy: OverflowPolicy::default(),
         }
     }
 
-    pub fn consume(&mut self, new_data: &[u8]) -> BufParserIter {
-        //
+    pub fn is_buffer_empty(&self) -> bool {
+        self.buf.len() == 0
+    }
+
+    pub fn buffer_len(&self) -> usize {
+        self.buf.len()
     }
+
+    /// Resynchronization and overflow counters accumulated so far
+    pub fn stats(&self) -> ParserStats {
+        self.stats
+    }
+
+    /// The policy applied when an incoming byte arrives with no room left
+    /// in the backing buffer
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: OverflowPolicy) {
+        self.policy = policy;
+    }
+
+    pub fn consume(&mut self, new_data: &[u8]) -> BufParserIter<'_, 'a> {
+        let mut overflowed = false;
+        let cap = self.policy.cap();
+        for &byte in new_data {
+            // Honor the configured logical cap first; whenever the cap is at
+            // or above the backing slice's physical capacity (as with the
+            // default `usize::MAX`), that capacity is the limiting factor
+            // instead, via `push`'s own return.
+            let pushed = self.buf.len() < cap && self.buf.push(byte);
+            if !pushed {
+                overflowed = true;
+                match self.policy {
+                    OverflowPolicy::Error(_) => break,
+                    OverflowPolicy::DropOldest(_) | OverflowPolicy::GrowToCap(_) => {
+                        self.buf.advance(1);
+                        self.buf.push(byte);
+                    }
+                }
+            }
+        }
+        if overflowed {
+            self.stats.dropped_frames += 1;
+        }
+        self.stats.high_water_mark = self.stats.high_water_mark.max(self.buf.len());
+        BufParserIter {
+            buf: &mut self.buf,
+            scratch: &mut self.scratch,
+            stats: &mut self.stats,
+            off: 0,
+        }
+    }
+}
+
+/// Iterator over packets found in a [`BufParser`]'s backing buffer
+pub struct BufParserIter<'i, 'a> {
+    buf: &'i mut CircularBuffer<'a>,
+    scratch: &'i mut [u8; SCRATCH_LEN],
+    stats: &'i mut ParserStats,
+    off: usize,
 }
 
-pub struct BufParserIter {
-    //
+impl<'i, 'a> Drop for BufParserIter<'i, 'a> {
+    fn drop(&mut self) {
+        self.buf.advance(self.off);
+    }
+}
+
+impl<'i, 'a> BufParserIter<'i, 'a> {
+    /// Scans forward from `self.off` for the next plausible frame, leaving
+    /// `self.off` pointing at it. Returns `None` (and advances `self.off`
+    /// as far as is safe) when no complete candidate is available yet.
+    fn scan(&mut self) -> Option<FrameLocation> {
+        loop {
+            let len = self.buf.len();
+            if self.off >= len {
+                return None;
+            }
+
+            let pos = match (self.off..len).find(|&i| self.buf.at(i) == SYNC_CHAR_1) {
+                Some(pos) => pos,
+                None => {
+                    self.stats.bytes_skipped += len - self.off;
+                    self.off = len;
+                    return None;
+                }
+            };
+
+            if pos + 1 >= len {
+                self.stats.bytes_skipped += pos - self.off;
+                self.off = pos;
+                return None;
+            }
+            if self.buf.at(pos + 1) != SYNC_CHAR_2 {
+                self.stats.bytes_skipped += pos + 1 - self.off;
+                self.off = pos + 1;
+                continue;
+            }
+
+            if pos + 5 >= len {
+                self.stats.bytes_skipped += pos - self.off;
+                self.off = pos;
+                return None;
+            }
+
+            let pack_len: usize =
+                u16::from_le_bytes([self.buf.at(pos + 4), self.buf.at(pos + 5)]).into();
+            if pack_len > usize::from(MAX_PAYLOAD_LEN) {
+                self.stats.bytes_skipped += pos + 2 - self.off;
+                self.off = pos + 2;
+                continue;
+            }
+
+            let frame_len = 8 + pack_len;
+            if pos + frame_len > len {
+                self.stats.bytes_skipped += pos - self.off;
+                self.off = pos;
+                return None;
+            }
+
+            self.stats.bytes_skipped += pos - self.off;
+            return Some(FrameLocation {
+                pos,
+                pack_len,
+                frame_len,
+            });
+        }
+    }
+
+    /// Analog of `core::iter::Iterator::next`, should be switched to
+    /// trait implmentation after merge of https://github.com/rust-lang/rust/issues/44265
+    pub fn next(&mut self) -> Option<Result<PacketRef, ParserError>> {
+        let FrameLocation {
+            pos,
+            pack_len,
+            frame_len,
+        } = self.scan()?;
+
+        // The frame from class id through the checksum, i.e. everything
+        // `match_packet` and `ubx_checksum` need, excluding the two sync bytes.
+        let body_len = 6 + pack_len;
+        let body = match self.buf.as_contiguous_slice(pos + 2, body_len) {
+            Some(slice) => slice,
+            None => {
+                for i in 0..body_len {
+                    self.scratch[i] = self.buf.at(pos + 2 + i);
+                }
+                &self.scratch[..body_len]
+            }
+        };
+
+        let (ck_a, ck_b) = ubx_checksum(&body[..4 + pack_len]);
+        let (expect_ck_a, expect_ck_b) = (body[4 + pack_len], body[4 + pack_len + 1]);
+
+        self.off = pos + frame_len;
+
+        if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
+            self.stats.checksum_failures += 1;
+            return Some(Err(ParserError::InvalidChecksum {
+                expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
+                got: u16::from_le_bytes([ck_a, ck_b]),
+            }));
+        }
+
+        let class_id = body[0];
+        let msg_id = body[1];
+        let msg_data = &body[4..4 + pack_len];
+        Some(match_packet(class_id, msg_id, msg_data))
+    }
+
+    /// Analog of [`Self::next`] that copies the verified frame into a block
+    /// drawn from `pool` instead of borrowing from the backing buffer. The
+    /// resulting [`PooledPacket`] owns its bytes, so it can be moved across
+    /// a task/ISR boundary and outlive this iterator. If the pool has no
+    /// free block the frame is still consumed from the buffer and dropped;
+    /// `None` is returned for that call.
+    pub fn next_pooled<'p, const N: usize>(
+        &mut self,
+        pool: &'p Pool<N>,
+    ) -> Option<Result<PooledPacket<'p, N>, ParserError>> {
+        let FrameLocation {
+            pos,
+            pack_len,
+            frame_len,
+        } = self.scan()?;
+
+        let body_len = 6 + pack_len;
+        for i in 0..body_len {
+            self.scratch[i] = self.buf.at(pos + 2 + i);
+        }
+        let body = &self.scratch[..body_len];
+
+        let (ck_a, ck_b) = ubx_checksum(&body[..4 + pack_len]);
+        let (expect_ck_a, expect_ck_b) = (body[4 + pack_len], body[4 + pack_len + 1]);
+
+        self.off = pos + frame_len;
+
+        if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
+            self.stats.checksum_failures += 1;
+            return Some(Err(ParserError::InvalidChecksum {
+                expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
+                got: u16::from_le_bytes([ck_a, ck_b]),
+            }));
+        }
+
+        let idx = pool.alloc()?;
+        pool.store(idx, body);
+        Some(Ok(PooledPacket { pool, idx }))
+    }
+
+    /// Scans forward from `self.off`, using only non-mutating peeks into the
+    /// circular buffer, for the next complete UBX, NMEA, or RTCM3 candidate.
+    /// A byte that merely looks like the start of a frame but doesn't check
+    /// out advances `self.off` by a single byte rather than a fixed skip, so
+    /// overlapping false positives are retried at every offset. Like `scan`,
+    /// this never itself confirms a checksum, only that `frame_len` bytes of
+    /// a plausible frame are fully buffered.
+    fn scan_frame(&mut self) -> Option<usize> {
+        loop {
+            let len = self.buf.len();
+            if self.off >= len {
+                return None;
+            }
+
+            let pos = self.off;
+            match self.buf.at(pos) {
+                SYNC_CHAR_1 => {
+                    if pos + 1 >= len {
+                        return None;
+                    }
+                    if self.buf.at(pos + 1) != SYNC_CHAR_2 {
+                        self.stats.bytes_skipped += 1;
+                        self.off += 1;
+                        continue;
+                    }
+                    if pos + 5 >= len {
+                        return None;
+                    }
+                    let pack_len: usize =
+                        u16::from_le_bytes([self.buf.at(pos + 4), self.buf.at(pos + 5)]).into();
+                    if pack_len > usize::from(MAX_PAYLOAD_LEN) {
+                        self.stats.bytes_skipped += 1;
+                        self.off += 1;
+                        continue;
+                    }
+                    let frame_len = 8 + pack_len;
+                    if pos + frame_len > len {
+                        return None;
+                    }
+                    return Some(frame_len);
+                }
+                NMEA_START_1 | NMEA_START_2 => {
+                    let scan_limit = len.min(pos + MAX_NMEA_LEN + 2);
+                    let end = (pos + 1..scan_limit.saturating_sub(1))
+                        .find(|&i| self.buf.at(i) == b'\r' && self.buf.at(i + 1) == b'\n');
+                    match end {
+                        // `attempt_nmea` additionally requires the whole
+                        // span to be valid UTF-8; checking ASCII here is a
+                        // cheap, non-mutating stand-in for that (ASCII is
+                        // always valid UTF-8), so a binary noise byte that
+                        // happens to land between a `$`/`!` and a `\r\n` is
+                        // rejected here rather than being handed to
+                        // `attempt_nmea` as if it were a confirmed frame.
+                        Some(end) if (pos..end + 2).all(|i| self.buf.at(i).is_ascii()) => {
+                            return Some(end + 2 - pos);
+                        }
+                        Some(_) => {
+                            self.stats.bytes_skipped += 1;
+                            self.off += 1;
+                            continue;
+                        }
+                        None if len - pos > MAX_NMEA_LEN => {
+                            self.stats.bytes_skipped += 1;
+                            self.off += 1;
+                            continue;
+                        }
+                        None => return None,
+                    }
+                }
+                RTCM3_PREAMBLE => {
+                    if pos + 3 > len {
+                        return None;
+                    }
+                    let payload_len = (usize::from(self.buf.at(pos + 1) & 0x03) << 8)
+                        | usize::from(self.buf.at(pos + 2));
+                    let frame_len = 3 + payload_len + 3;
+                    if pos + frame_len > len {
+                        return None;
+                    }
+                    return Some(frame_len);
+                }
+                _ => {
+                    self.stats.bytes_skipped += 1;
+                    self.off += 1;
+                    continue;
+                }
+            }
+        }
+    }
+
+    /// Analog of [`Self::next`] that also demultiplexes NMEA and RTCM3
+    /// frames interleaved with UBX on the same stream, yielding a tagged
+    /// [`Frame`]
+    pub fn next_frame(&mut self) -> Option<Result<Frame, ParserError>> {
+        let frame_len = self.scan_frame()?;
+        let pos = self.off;
+
+        for i in 0..frame_len {
+            self.scratch[i] = self.buf.at(pos + i);
+        }
+        let data = &self.scratch[..frame_len];
+
+        let attempt = match data[0] {
+            SYNC_CHAR_1 => attempt_ubx(data),
+            NMEA_START_1 | NMEA_START_2 => attempt_nmea(data),
+            RTCM3_PREAMBLE => attempt_rtcm3(data),
+            _ => unreachable!("scan_frame only returns positions at a recognized marker"),
+        };
+
+        match attempt {
+            // `scan_frame` already confirmed `frame_len` bytes of a complete
+            // candidate are buffered, and (for NMEA) that its body is
+            // ASCII, so the only outcome left is a parsed (possibly
+            // checksum-mismatched) frame.
+            FrameAttempt::Frame { frame_len, result } => {
+                if result.is_err() {
+                    self.stats.checksum_failures += 1;
+                }
+                self.off = pos + frame_len;
+                Some(result)
+            }
+            FrameAttempt::Incomplete | FrameAttempt::Invalid => {
+                unreachable!("scan_frame already validated this candidate's framing and length")
+            }
+        }
+    }
 }
 
 /// Streaming parser for UBX protocol with buffer
 #[derive(Default)]
 pub struct Parser {
     buf: Vec<u8>,
+    stats: ParserStats,
+    policy: OverflowPolicy,
 }
 
 impl Parser {
@@ -179,41 +844,77 @@ impl Parser {
         self.buf.len()
     }
 
+    /// Resynchronization and overflow counters accumulated so far
+    pub fn stats(&self) -> ParserStats {
+        self.stats
+    }
+
+    /// The policy applied when the buffer has grown past its configured cap
+    /// without yet containing a complete frame
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: OverflowPolicy) {
+        self.policy = policy;
+    }
+
     pub fn consume(&mut self, new_data: &[u8]) -> ParserIter {
-        match self
-            .buf
-            .iter()
-            .chain(new_data.iter())
-            .position(|x| *x == SYNC_CHAR_1)
-        {
+        let mut off = match self.buf.iter().chain(new_data.iter()).position(|x| {
+            matches!(
+                *x,
+                SYNC_CHAR_1 | NMEA_START_1 | NMEA_START_2 | RTCM3_PREAMBLE
+            )
+        }) {
             Some(mut off) => {
                 if off >= self.buf.len() {
+                    self.stats.bytes_skipped += off;
                     off -= self.buf.len();
                     self.buf.clear();
                     self.buf.extend_from_slice(&new_data[off..]);
-                    off = 0;
+                    0
                 } else {
                     self.buf.extend_from_slice(new_data);
-                }
-                ParserIter {
-                    buf: &mut self.buf,
-                    off,
+                    off
                 }
             }
             None => {
+                self.stats.bytes_skipped += self.buf.len() + new_data.len();
                 self.buf.clear();
-                ParserIter {
-                    buf: &mut self.buf,
-                    off: 0,
+                0
+            }
+        };
+
+        let cap = self.policy.cap();
+        if self.buf.len() > self.policy.overflow_threshold() {
+            self.stats.dropped_frames += 1;
+            match self.policy {
+                OverflowPolicy::Error(_) => {
+                    self.buf.truncate(cap);
+                    off = off.min(self.buf.len());
+                }
+                OverflowPolicy::DropOldest(_) | OverflowPolicy::GrowToCap(_) => {
+                    let excess = self.buf.len() - cap;
+                    self.buf.drain(0..excess);
+                    off = off.saturating_sub(excess);
                 }
             }
         }
+
+        self.stats.high_water_mark = self.stats.high_water_mark.max(self.buf.len());
+
+        ParserIter {
+            buf: &mut self.buf,
+            stats: &mut self.stats,
+            off,
+        }
     }
 }
 
 /// Iterator over data stored in `Parser` buffer
 pub struct ParserIter<'a> {
     buf: &'a mut Vec<u8>,
+    stats: &'a mut ParserStats,
     off: usize,
 }
 
@@ -226,9 +927,9 @@ impl<'a> Drop for ParserIter<'a> {
 }
 
 impl<'a> ParserIter<'a> {
-    /// Analog of `core::iter::Iterator::next`, should be switched to
-    /// trait implmentation after merge of https://github.com/rust-lang/rust/issues/44265
-    pub fn next(&mut self) -> Option<Result<PacketRef, ParserError>> {
+    /// Scans forward from `self.off` for the next plausible frame, leaving
+    /// `self.off` pointing at it.
+    fn scan(&mut self) -> Option<FrameLocation> {
         while self.off < self.buf.len() {
             let data = &self.buf[self.off..];
             let pos = data.iter().position(|x| *x == SYNC_CHAR_1)?;
@@ -238,7 +939,12 @@ impl<'a> ParserIter<'a> {
                 return None;
             }
             if maybe_pack[1] != SYNC_CHAR_2 {
-                self.off += pos + 2;
+                // Advance past only the byte at `pos`, not `pos + 1` too: a
+                // mismatched second sync byte could itself be `SYNC_CHAR_1`
+                // and start a genuine frame one byte later, which a fixed
+                // two-byte skip would step over.
+                self.stats.bytes_skipped += pos + 1;
+                self.off += pos + 1;
                 continue;
             }
 
@@ -248,34 +954,639 @@ impl<'a> ParserIter<'a> {
 
             let pack_len: usize = u16::from_le_bytes([maybe_pack[4], maybe_pack[5]]).into();
             if pack_len > usize::from(MAX_PAYLOAD_LEN) {
+                self.stats.bytes_skipped += pos + 2;
                 self.off += pos + 2;
                 continue;
             }
-            if (pack_len + 6 + 2) > maybe_pack.len() {
+            let frame_len = 8 + pack_len;
+            if frame_len > maybe_pack.len() {
                 return None;
             }
-            let (ck_a, ck_b) = ubx_checksum(&maybe_pack[2..(4 + pack_len + 2)]);
 
-            let (expect_ck_a, expect_ck_b) =
-                (maybe_pack[6 + pack_len], maybe_pack[6 + pack_len + 1]);
-            if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
-                self.off += pos + 2;
-                return Some(Err(ParserError::InvalidChecksum {
-                    expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
-                    got: u16::from_le_bytes([ck_a, ck_b]),
-                }));
-            }
-            let msg_data = &maybe_pack[6..(6 + pack_len)];
-            let class_id = maybe_pack[2];
-            let msg_id = maybe_pack[3];
-            self.off += pos + 6 + pack_len + 2;
-            return Some(match_packet(class_id, msg_id, msg_data));
+            self.stats.bytes_skipped += pos;
+            return Some(FrameLocation {
+                pos: self.off + pos,
+                pack_len,
+                frame_len,
+            });
         }
         None
     }
+
+    /// Analog of `core::iter::Iterator::next`, should be switched to
+    /// trait implmentation after merge of https://github.com/rust-lang/rust/issues/44265
+    pub fn next(&mut self) -> Option<Result<PacketRef, ParserError>> {
+        let FrameLocation {
+            pos,
+            pack_len,
+            frame_len,
+        } = self.scan()?;
+        let maybe_pack = &self.buf[pos..];
+
+        let (ck_a, ck_b) = ubx_checksum(&maybe_pack[2..(4 + pack_len + 2)]);
+        let (expect_ck_a, expect_ck_b) = (maybe_pack[6 + pack_len], maybe_pack[6 + pack_len + 1]);
+
+        self.off = pos + frame_len;
+
+        if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
+            self.stats.checksum_failures += 1;
+            return Some(Err(ParserError::InvalidChecksum {
+                expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
+                got: u16::from_le_bytes([ck_a, ck_b]),
+            }));
+        }
+        let msg_data = &maybe_pack[6..(6 + pack_len)];
+        let class_id = maybe_pack[2];
+        let msg_id = maybe_pack[3];
+        Some(match_packet(class_id, msg_id, msg_data))
+    }
+
+    /// Analog of [`Self::next`] that copies the verified frame into a block
+    /// drawn from `pool` instead of borrowing from `self.buf`, so the
+    /// resulting [`PooledPacket`] can outlive this iterator. If the pool has
+    /// no free block the frame is still consumed and dropped; `None` is
+    /// returned for that call.
+    pub fn next_pooled<'p, const N: usize>(
+        &mut self,
+        pool: &'p Pool<N>,
+    ) -> Option<Result<PooledPacket<'p, N>, ParserError>> {
+        let FrameLocation {
+            pos,
+            pack_len,
+            frame_len,
+        } = self.scan()?;
+        let maybe_pack = &self.buf[pos..];
+
+        let (ck_a, ck_b) = ubx_checksum(&maybe_pack[2..(4 + pack_len + 2)]);
+        let (expect_ck_a, expect_ck_b) = (maybe_pack[6 + pack_len], maybe_pack[6 + pack_len + 1]);
+        // `body` must include the trailing checksum bytes: `PooledPacket::packet`
+        // expects the same class/id/len/payload/checksum layout that `store`
+        // copies in, same as `BufParserIter::next_pooled` above.
+        let body = &maybe_pack[2..(8 + pack_len)];
+
+        self.off = pos + frame_len;
+
+        if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
+            self.stats.checksum_failures += 1;
+            return Some(Err(ParserError::InvalidChecksum {
+                expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
+                got: u16::from_le_bytes([ck_a, ck_b]),
+            }));
+        }
+
+        let idx = pool.alloc()?;
+        pool.store(idx, body);
+        Some(Ok(PooledPacket { pool, idx }))
+    }
+
+    /// Analog of [`Self::next`] that also demultiplexes NMEA and RTCM3
+    /// frames interleaved with UBX on the same stream, yielding a tagged
+    /// [`Frame`]. A byte that merely looks like the start of a frame but
+    /// doesn't check out advances the scan by a single byte rather than a
+    /// fixed skip, so overlapping false positives are retried at every offset.
+    pub fn next_frame(&mut self) -> Option<Result<Frame, ParserError>> {
+        loop {
+            if self.off >= self.buf.len() {
+                return None;
+            }
+            let data = &self.buf[self.off..];
+            let attempt = match data[0] {
+                SYNC_CHAR_1 => attempt_ubx(data),
+                NMEA_START_1 | NMEA_START_2 => attempt_nmea(data),
+                RTCM3_PREAMBLE => attempt_rtcm3(data),
+                _ => FrameAttempt::Invalid,
+            };
+
+            match attempt {
+                FrameAttempt::Incomplete => return None,
+                FrameAttempt::Invalid => {
+                    self.stats.bytes_skipped += 1;
+                    self.off += 1;
+                    continue;
+                }
+                FrameAttempt::Frame { frame_len, result } => {
+                    if result.is_err() {
+                        self.stats.checksum_failures += 1;
+                    }
+                    self.off += frame_len;
+                    return Some(result);
+                }
+            }
+        }
+    }
+}
+
+/// A lock-free, statically-sized pool of packet-sized blocks, used to hand
+/// parsed packets from an ISR producer to a main-loop consumer without
+/// allocation or locking. Claiming and releasing a block is a single CAS
+/// loop over a free-slot bitmask, in the spirit of the free-list pools in
+/// crates like `heapless`, but without depending on one.
+///
+/// `N` must not exceed the bit width of `usize` on the target.
+pub struct Pool<const N: usize> {
+    free_mask: AtomicUsize,
+    blocks: [UnsafeCell<PoolBlock>; N],
+}
+
+struct PoolBlock {
+    data: [u8; SCRATCH_LEN],
+    len: usize,
+}
+
+// Safety: access to each block is gated by a successful CAS on `free_mask`
+// in `alloc`/`free`, so at most one `PooledPacket` ever references a given
+// block's `UnsafeCell` at a time.
+unsafe impl<const N: usize> Sync for Pool<N> {}
+
+impl<const N: usize> Pool<N> {
+    pub fn new() -> Self {
+        let initial_mask = if N >= usize::BITS as usize {
+            usize::MAX
+        } else {
+            (1usize << N) - 1
+        };
+        Pool {
+            free_mask: AtomicUsize::new(initial_mask),
+            blocks: core::array::from_fn(|_| {
+                UnsafeCell::new(PoolBlock {
+                    data: [0; SCRATCH_LEN],
+                    len: 0,
+                })
+            }),
+        }
+    }
+
+    /// Claims a free block, returning its index, or `None` if the pool is
+    /// exhausted
+    fn alloc(&self) -> Option<usize> {
+        loop {
+            let mask = self.free_mask.load(Ordering::Acquire);
+            if mask == 0 {
+                return None;
+            }
+            let idx = mask.trailing_zeros() as usize;
+            let new_mask = mask & !(1 << idx);
+            if self
+                .free_mask
+                .compare_exchange_weak(mask, new_mask, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return Some(idx);
+            }
+        }
+    }
+
+    /// Returns block `idx` to the pool
+    fn free(&self, idx: usize) {
+        loop {
+            let mask = self.free_mask.load(Ordering::Acquire);
+            let new_mask = mask | (1 << idx);
+            if self
+                .free_mask
+                .compare_exchange_weak(mask, new_mask, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    /// Writes `frame` into block `idx`, which the caller must have just
+    /// gotten from `alloc` and not yet handed out as a `PooledPacket`
+    fn store(&self, idx: usize, frame: &[u8]) {
+        let block = unsafe { &mut *self.blocks[idx].get() };
+        block.data[..frame.len()].copy_from_slice(frame);
+        block.len = frame.len();
+    }
+}
+
+impl<const N: usize> Default for Pool<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An owned, checksum-verified UBX packet drawn from a [`Pool`]. The
+/// underlying block is returned to the pool when this handle is dropped, so
+/// it can be moved across a task/ISR boundary and outlive the iterator that
+/// produced it.
+pub struct PooledPacket<'p, const N: usize> {
+    pool: &'p Pool<N>,
+    idx: usize,
+}
+
+impl<'p, const N: usize> PooledPacket<'p, N> {
+    /// The raw frame bytes backing this packet: class, id, length, payload,
+    /// then checksum
+    pub fn frame(&self) -> &[u8] {
+        // Safety: this handle is the sole owner of `idx` until it is dropped
+        let block = unsafe { &*self.pool.blocks[self.idx].get() };
+        &block.data[..block.len]
+    }
+
+    /// Parses the owned frame bytes into a [`PacketRef`]
+    pub fn packet(&self) -> Result<PacketRef, ParserError> {
+        let frame = self.frame();
+        let class_id = frame[0];
+        let msg_id = frame[1];
+        let msg_data = &frame[4..frame.len() - 2];
+        match_packet(class_id, msg_id, msg_data)
+    }
+}
+
+impl<'p, const N: usize> Drop for PooledPacket<'p, N> {
+    fn drop(&mut self) {
+        self.pool.free(self.idx);
+    }
+}
+
+/// A lock-free single-producer/single-consumer byte ring, for feeding bytes
+/// from a UART ISR into a parser running in the main loop without a mutex.
+/// Use [`SpscRing::split`] to obtain a [`Producer`] for the ISR side and a
+/// [`Consumer`] for the task side.
+pub struct SpscRing<'a> {
+    buf: *mut u8,
+    capacity: usize,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+    _buf: PhantomData<&'a mut [u8]>,
+}
+
+// Safety: `head` is only ever written by the `Consumer` and `tail` only by
+// the `Producer`, so the two sides never race on the same atomic, and each
+// only reads/writes the region of `buf` the other has already published.
+unsafe impl<'a> Sync for SpscRing<'a> {}
+
+impl<'a> SpscRing<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        SpscRing {
+            capacity: buf.len(),
+            buf: buf.as_mut_ptr(),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            _buf: PhantomData,
+        }
+    }
+
+    /// Splits the ring into its producer and consumer halves
+    pub fn split(&mut self) -> (Producer<'_, 'a>, Consumer<'_, 'a>) {
+        (Producer { ring: self }, Consumer { ring: self })
+    }
+}
+
+/// The ISR-side handle of an [`SpscRing`]
+pub struct Producer<'r, 'a> {
+    ring: &'r SpscRing<'a>,
+}
+
+impl<'r, 'a> Producer<'r, 'a> {
+    /// Pushes a single byte, returning `false` if the ring is full
+    pub fn push(&mut self, byte: u8) -> bool {
+        let capacity = self.ring.capacity;
+        let head = self.ring.head.load(Ordering::Acquire);
+        let tail = self.ring.tail.load(Ordering::Relaxed);
+        if (tail + 1) % capacity == head {
+            return false;
+        }
+        // Safety: only the `Producer` ever writes through this pointer, and
+        // only at `tail`, which is always in bounds and which the
+        // `Consumer` never writes past `head`. Writing through a single
+        // in-bounds offset (rather than reconstituting a slice over the
+        // whole buffer) keeps this sound even though the `Consumer` holds
+        // a live read pointer into the same allocation.
+        unsafe { self.ring.buf.add(tail).write(byte) };
+        self.ring.tail.store((tail + 1) % capacity, Ordering::Release);
+        true
+    }
+}
+
+/// The main-loop-side handle of an [`SpscRing`]
+pub struct Consumer<'r, 'a> {
+    ring: &'r SpscRing<'a>,
+}
+
+impl<'r, 'a> Consumer<'r, 'a> {
+    /// Pops a single byte, returning `None` if the ring is empty
+    pub fn pop(&mut self) -> Option<u8> {
+        let capacity = self.ring.capacity;
+        let tail = self.ring.tail.load(Ordering::Acquire);
+        let head = self.ring.head.load(Ordering::Relaxed);
+        if head == tail {
+            return None;
+        }
+        // Safety: only the `Consumer` ever reads through this pointer, and
+        // only at `head`, which is always in bounds and which the
+        // `Producer` never writes past `tail`.
+        let byte = unsafe { self.ring.buf.add(head).read() };
+        self.ring.head.store((head + 1) % capacity, Ordering::Release);
+        Some(byte)
+    }
 }
 
 #[test]
 fn test_max_payload_len() {
     assert!(MAX_PAYLOAD_LEN >= 1240);
 }
+
+#[cfg(test)]
+mod spsc_ring_test {
+    use super::*;
+
+    #[test]
+    fn push_pop_round_trips_in_order() {
+        let mut backing = [0u8; 4];
+        let mut ring = SpscRing::new(&mut backing);
+        let (mut producer, mut consumer) = ring.split();
+
+        assert!(producer.push(1));
+        assert!(producer.push(2));
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn push_fails_once_full_and_wraps_after_popping() {
+        let mut backing = [0u8; 4];
+        let mut ring = SpscRing::new(&mut backing);
+        let (mut producer, mut consumer) = ring.split();
+
+        // One slot is always left empty to disambiguate full from empty.
+        assert!(producer.push(1));
+        assert!(producer.push(2));
+        assert!(producer.push(3));
+        assert!(!producer.push(4));
+
+        assert_eq!(consumer.pop(), Some(1));
+        // Tail wraps past the physical end of `backing` here; head follows
+        // once 4 is popped.
+        assert!(producer.push(4));
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), Some(3));
+        assert_eq!(consumer.pop(), Some(4));
+        assert_eq!(consumer.pop(), None);
+    }
+}
+
+#[cfg(test)]
+mod frame_demux_test {
+    use super::*;
+
+    fn ubx_frame(class_id: u8, msg_id: u8, payload: &[u8]) -> Vec<u8> {
+        let mut frame = Vec::new();
+        frame.push(SYNC_CHAR_1);
+        frame.push(SYNC_CHAR_2);
+        frame.push(class_id);
+        frame.push(msg_id);
+        frame.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+        frame.extend_from_slice(payload);
+        let (ck_a, ck_b) = ubx_checksum(&frame[2..]);
+        frame.push(ck_a);
+        frame.push(ck_b);
+        frame
+    }
+
+    fn rtcm3_frame(payload: &[u8]) -> Vec<u8> {
+        let mut frame = Vec::new();
+        frame.push(RTCM3_PREAMBLE);
+        frame.push(((payload.len() >> 8) & 0x03) as u8);
+        frame.push((payload.len() & 0xFF) as u8);
+        frame.extend_from_slice(payload);
+        let crc = rtcm3_crc24q(&frame);
+        frame.push(((crc >> 16) & 0xFF) as u8);
+        frame.push(((crc >> 8) & 0xFF) as u8);
+        frame.push((crc & 0xFF) as u8);
+        frame
+    }
+
+    fn interleaved_stream() -> Vec<u8> {
+        let mut stream = Vec::new();
+        stream.extend_from_slice(b"$GPGGA,noise*04\r\n");
+        stream.extend_from_slice(&ubx_frame(0x01, 0x02, &[1, 2, 3, 4]));
+        stream.extend_from_slice(&rtcm3_frame(&[0xaa, 0xbb]));
+        stream
+    }
+
+    #[test]
+    fn parser_next_frame_demuxes_ubx_nmea_rtcm3() {
+        let mut parser = Parser::default();
+        let stream = interleaved_stream();
+        let mut it = parser.consume(&stream);
+
+        match it.next_frame() {
+            Some(Ok(Frame::Nmea(s))) => assert!(s.starts_with("$GPGGA")),
+            _ => panic!("expected an NMEA frame"),
+        }
+        assert!(matches!(it.next_frame(), Some(Ok(Frame::Ubx(_)))));
+        assert!(matches!(it.next_frame(), Some(Ok(Frame::Rtcm3(_)))));
+        assert!(it.next_frame().is_none());
+    }
+
+    #[test]
+    fn buf_parser_next_frame_demuxes_ubx_nmea_rtcm3() {
+        let mut backing = [0u8; 256];
+        let mut buf_parser = BufParser::new(&mut backing);
+        let stream = interleaved_stream();
+        let mut it = buf_parser.consume(&stream);
+
+        match it.next_frame() {
+            Some(Ok(Frame::Nmea(s))) => assert!(s.starts_with("$GPGGA")),
+            _ => panic!("expected an NMEA frame"),
+        }
+        assert!(matches!(it.next_frame(), Some(Ok(Frame::Ubx(_)))));
+        assert!(matches!(it.next_frame(), Some(Ok(Frame::Rtcm3(_)))));
+        assert!(it.next_frame().is_none());
+    }
+
+    #[test]
+    fn buf_parser_next_parses_ubx_frame_straddling_the_physical_wrap() {
+        // A 16-byte backing ring holds 15 logical bytes. Consume and fully
+        // drain an 11-byte frame first so the ring's head/tail sit at
+        // physical offset 11, then feed a second 10-byte frame: its bytes
+        // land at physical offsets 11..21 (mod 16), straddling the backing
+        // slice's physical end and forcing the scratch-copy fallback in
+        // `BufParserIter::next` rather than the contiguous-slice fast path.
+        let mut backing = [0u8; 16];
+        let mut buf_parser = BufParser::new(&mut backing);
+
+        let first = ubx_frame(0x01, 0x02, &[1, 2, 3]);
+        assert_eq!(first.len(), 11);
+        let mut it = buf_parser.consume(&first);
+        assert!(matches!(it.next(), Some(Ok(_))));
+        drop(it);
+
+        let second = ubx_frame(0x03, 0x04, &[9, 9]);
+        assert_eq!(second.len(), 10);
+        let mut it = buf_parser.consume(&second);
+        assert!(matches!(it.next(), Some(Ok(_))));
+    }
+
+    #[test]
+    fn buf_parser_next_frame_resyncs_past_non_utf8_nmea_false_positive() {
+        // A `$` followed by a non-UTF-8 byte and then a `\r\n` looks like a
+        // complete NMEA sentence by framing alone, but isn't valid text.
+        let mut backing = [0u8; 64];
+        let mut buf_parser = BufParser::new(&mut backing);
+        let mut it = buf_parser.consume(&[0x24, 0xFF, 0x0D, 0x0A]);
+        assert!(it.next_frame().is_none());
+    }
+
+    #[test]
+    fn parser_scan_resyncs_by_one_byte_past_doubled_sync_char() {
+        // A spurious extra SYNC_CHAR_1 right before a real frame looks like a
+        // frame with a bad second sync byte, but the real frame starts just
+        // one byte later; a fixed two-byte skip would step over it.
+        let mut parser = Parser::default();
+        let mut stream = Vec::new();
+        stream.push(SYNC_CHAR_1);
+        stream.extend_from_slice(&ubx_frame(0x05, 0x06, &[9, 9]));
+        let mut it = parser.consume(&stream);
+        assert!(matches!(it.next(), Some(Ok(_))));
+    }
+
+    #[test]
+    fn parser_next_pooled_round_trips_class_id_and_payload() {
+        let mut parser = Parser::default();
+        let payload = [1, 2, 3, 4];
+        let frame = ubx_frame(0x01, 0x02, &payload);
+        let mut it = parser.consume(&frame);
+
+        let pool: Pool<1> = Pool::new();
+        let pooled = it.next_pooled(&pool).expect("frame").expect("checksum ok");
+        assert!(pooled.packet().is_ok());
+        let bytes = pooled.frame();
+        assert_eq!(bytes[0], 0x01);
+        assert_eq!(bytes[1], 0x02);
+        assert_eq!(&bytes[4..4 + payload.len()], &payload[..]);
+    }
+
+    #[test]
+    fn buf_parser_next_pooled_round_trips_class_id_and_payload() {
+        let mut backing = [0u8; 256];
+        let mut buf_parser = BufParser::new(&mut backing);
+        let payload = [5, 6, 7];
+        let frame = ubx_frame(0x03, 0x04, &payload);
+        let mut it = buf_parser.consume(&frame);
+
+        let pool: Pool<1> = Pool::new();
+        let pooled = it.next_pooled(&pool).expect("frame").expect("checksum ok");
+        assert!(pooled.packet().is_ok());
+        let bytes = pooled.frame();
+        assert_eq!(bytes[0], 0x03);
+        assert_eq!(bytes[1], 0x04);
+        assert_eq!(&bytes[4..4 + payload.len()], &payload[..]);
+    }
+}
+
+#[cfg(test)]
+mod stats_test {
+    use super::*;
+
+    #[test]
+    fn parser_counts_checksum_failures() {
+        let mut parser = Parser::default();
+        let frame = [SYNC_CHAR_1, SYNC_CHAR_2, 0x01, 0x02, 0, 0, 0xff, 0xff];
+        let mut it = parser.consume(&frame);
+        assert!(matches!(it.next(), Some(Err(ParserError::InvalidChecksum { .. }))));
+        drop(it);
+        assert_eq!(parser.stats().checksum_failures, 1);
+    }
+
+    #[test]
+    fn parser_counts_skipped_bytes_on_resync() {
+        let mut parser = Parser::default();
+        // Two bytes of noise, then a sync char with a bad second sync byte,
+        // then a real, valid frame.
+        let (ck_a, ck_b) = ubx_checksum(&[0x01, 0x02, 0, 0]);
+        let data = [
+            0x00,
+            0x00,
+            SYNC_CHAR_1,
+            0x00,
+            SYNC_CHAR_1,
+            SYNC_CHAR_2,
+            0x01,
+            0x02,
+            0,
+            0,
+            ck_a,
+            ck_b,
+        ];
+        let mut it = parser.consume(&data);
+        assert!(matches!(it.next(), Some(Ok(_))));
+        drop(it);
+        assert_eq!(parser.stats().bytes_skipped, 4);
+    }
+
+    #[test]
+    fn parser_counts_skipped_bytes_when_no_marker_is_found() {
+        let mut parser = Parser::default();
+        let garbage = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        drop(parser.consume(&garbage));
+        assert_eq!(parser.buffer_len(), 0);
+        assert_eq!(parser.stats().bytes_skipped, garbage.len());
+    }
+
+    #[test]
+    fn buf_parser_drop_oldest_policy_keeps_newest_bytes() {
+        let mut backing = [0u8; 4];
+        let mut buf_parser = BufParser::new(&mut backing);
+        buf_parser.set_policy(OverflowPolicy::DropOldest(usize::MAX));
+        // The backing ring has room for 3 logical bytes (one slot is the
+        // sentinel used to disambiguate full from empty).
+        drop(buf_parser.consume(&[1, 2, 3, 4, 5]));
+        assert_eq!(buf_parser.buffer_len(), 3);
+        assert_eq!(buf_parser.stats().dropped_frames, 1);
+    }
+
+    #[test]
+    fn buf_parser_error_policy_drops_incoming_bytes() {
+        let mut backing = [0u8; 4];
+        let mut buf_parser = BufParser::new(&mut backing);
+        buf_parser.set_policy(OverflowPolicy::Error(usize::MAX));
+        drop(buf_parser.consume(&[1, 2, 3, 4, 5]));
+        assert_eq!(buf_parser.buffer_len(), 3);
+        assert_eq!(buf_parser.stats().dropped_frames, 1);
+    }
+
+    #[test]
+    fn buf_parser_drop_oldest_policy_honors_cap_below_physical_capacity() {
+        // Backing ring has room for 15 logical bytes, but the configured cap
+        // is well below that, so the cap itself (not the physical backing
+        // slice) must be what bounds `buffer_len`.
+        let mut backing = [0u8; 16];
+        let mut buf_parser = BufParser::new(&mut backing);
+        buf_parser.set_policy(OverflowPolicy::DropOldest(3));
+        drop(buf_parser.consume(&[1, 2, 3, 4, 5]));
+        assert_eq!(buf_parser.buffer_len(), 3);
+        assert_eq!(buf_parser.stats().dropped_frames, 1);
+    }
+
+    #[test]
+    fn parser_grow_to_cap_policy_grows_past_cap_before_trimming() {
+        let mut parser = Parser::default();
+        parser.set_policy(OverflowPolicy::GrowToCap(4));
+        // Anchored by the leading sync byte, so the whole chunk is kept
+        // (pending completion of the frame) rather than discarded outright.
+        // 6 bytes is past `cap` (4) but still under the
+        // `GROW_TO_CAP_FACTOR`-scaled threshold (8), so nothing is trimmed
+        // yet -- this is the growth `GrowToCap` is meant to allow.
+        drop(parser.consume(&[SYNC_CHAR_1, 0x00, 0x00, 0x00, 0x00, 0x00]));
+        assert_eq!(parser.buffer_len(), 6);
+        assert_eq!(parser.stats().dropped_frames, 0);
+
+        // Pushes the buffer past the scaled threshold, so it's trimmed back
+        // down to `cap`, same as `DropOldest` would.
+        drop(parser.consume(&[0x00, 0x00, 0x00]));
+        assert_eq!(parser.buffer_len(), 4);
+        assert_eq!(parser.stats().dropped_frames, 1);
+    }
+
+    #[test]
+    fn high_water_mark_tracks_largest_buffered_len() {
+        let mut parser = Parser::default();
+        drop(parser.consume(&[SYNC_CHAR_1, 0x00, 0x00]));
+        drop(parser.consume(&[0x00]));
+        assert!(parser.stats().high_water_mark >= 3);
+    }
+}